@@ -1,7 +1,10 @@
 use bevy_utils::HashMap;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 
-use crate::system::{BoxedSystem, Command, IntoSystem};
+use crate::system::{BoxedSystem, Command, IntoSystem, System};
 use crate::world::{Mut, World};
 // Needed for derive(Component) macro
 use crate::{self as bevy_ecs};
@@ -18,12 +21,6 @@ use bevy_ecs_macros::Resource;
 /// However, it will likely be easier to use the corresponding methods on [`World`],
 /// to avoid having to worry about split mutable borrows yourself.
 ///
-/// # Limitations
-///
-///  - stored systems cannot be chained: they can neither have an [`In`](crate::system::In) nor return any values
-///  - stored systems cannot recurse: they cannot run other systems via the [`SystemRegistry`] methods on `World` or `Commands`
-///  - exclusive systems cannot be used
-///
 /// # Examples
 ///
 /// You can run a single system directly on the World,
@@ -33,7 +30,7 @@ use bevy_ecs_macros::Resource;
 /// ```rust
 /// use bevy_ecs::prelude::*;
 ///
-/// let mut world = World::new();  
+/// let mut world = World::new();
 ///
 /// #[derive(Default, PartialEq, Debug)]
 /// struct Counter(u8);
@@ -73,35 +70,242 @@ use bevy_ecs_macros::Resource;
 /// world.run_system(spawn_7_entities);
 /// world.run_system(assert_7_spawned);
 /// ```
+///
+/// Systems that take an [`In`](crate::system::In) and/or return a value can be run with
+/// [`World::run_system_with_input`] (or [`SystemRegistry::run_with_input`]), which hands the
+/// input to the system and hands the system's output back to the caller.
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+///
+/// let mut world = World::new();
+///
+/// fn add_one(In(value): In<u32>) -> u32 {
+///     value + 1
+/// }
+///
+/// let result = world.run_system_with_input(add_one, 1);
+/// assert_eq!(result, 2);
+/// ```
 #[derive(Resource, Default)]
 pub struct SystemRegistry {
     last_id: u32,
-    systems: HashMap<u32, (bool, BoxedSystem)>,
+    systems: HashMap<u32, SystemEntry>,
+    /// Maps the concrete [`IntoSystem`] type that was registered to the [`SystemId`] it was
+    /// given, so that [`SystemRegistry::register`] can be idempotent.
+    ids_by_type: HashMap<TypeId, u32>,
+    /// An optional cap on how many one-shot systems may be nested inside one another via
+    /// [`Commands::run_system`](crate::system::Commands::run_system) (and the other `Commands`
+    /// run methods) before recursing further is refused. `None` (the default) means no limit is
+    /// enforced.
+    ///
+    /// Since `Commands::run_system` always funnels through the infallible
+    /// [`World::run_system`]/[`World::run_exclusive_system`] entry points, exceeding the limit
+    /// this way panics; only the fallible `*_by_id` methods (e.g.
+    /// [`World::run_system_by_id`]) surface it as
+    /// [`SystemRegistryError::RecursionLimitExceeded`] instead.
+    max_recursion_depth: Option<u32>,
+    /// Stores registered exclusive (`&mut World`) systems, separately from `systems` since they
+    /// are run directly against the `World` rather than through the regular system param machinery.
+    exclusive_systems: HashMap<u32, (bool, BoxedSystem)>,
+    /// Maps the concrete [`IntoSystem`] type that was registered to the [`ExclusiveSystemId`] it
+    /// was given, so that [`SystemRegistry::register_exclusive`] can be idempotent.
+    exclusive_ids_by_type: HashMap<TypeId, u32>,
 }
 
-/// A wrapper type for TypeId.
-/// It identifies a system that is registered in the [`SystemRegistry`].
+/// Resource used to defer running one-shot systems that are queued (via
+/// [`Commands::run_system`](crate::system::Commands::run_system) and friends) while the
+/// [`SystemRegistry`] is temporarily removed from the [`World`] to run another system.
+///
+/// Queueing the run instead of performing it immediately is what allows one-shot systems to
+/// recurse: the queue is drained once the [`SystemRegistry`] has been returned to the `World`.
+///
+/// Each queued run is tagged with the recursion depth of the system that queued it (`+1`), so
+/// that sibling systems dispatched one after another from the same parent are recognized as
+/// being at the same depth, rather than the depth being inflated by however many drain calls
+/// happen to still be on the Rust call stack when a later sibling is popped.
+#[derive(Resource, Default)]
+struct SystemRegistryRunQueue {
+    queue: VecDeque<(u32, Box<dyn FnOnce(&mut World) + Send>)>,
+    /// The recursion depth of whichever queued run is currently executing, used to tag any
+    /// runs that it defers in turn.
+    current_depth: u32,
+}
+
+/// A single entry in [`SystemRegistry::systems`].
+struct SystemEntry {
+    initialized: bool,
+    /// Cached at registration time so [`SystemRegistry::system_name`] can report it without
+    /// having to downcast back to the system's concrete `In`/`Out` types.
+    name: Cow<'static, str>,
+    system: Box<dyn Any + Send + Sync>,
+}
+
+/// A wrapper type identifying a system that is registered in the [`SystemRegistry`].
+///
+/// The `I` and `O` type parameters track the [`In`](crate::system::In) and return types of the
+/// system this id refers to, so that [`SystemRegistry::run_by_id_with_input`] can recover the
+/// correctly-typed system.
+pub struct SystemId<I = (), O = ()> {
+    id: u32,
+    marker: PhantomData<fn(I) -> O>,
+}
+
+impl<I, O> SystemId<I, O> {
+    fn new(id: u32) -> Self {
+        Self {
+            id,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O> Clone for SystemId<I, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, O> Copy for SystemId<I, O> {}
+
+impl<I, O> std::fmt::Debug for SystemId<I, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemId").field("id", &self.id).finish()
+    }
+}
+
+/// A wrapper type identifying an exclusive (`&mut World`) system that is registered in the
+/// [`SystemRegistry`].
 #[derive(Debug, Clone, Copy)]
-pub struct SystemId(u32);
+pub struct ExclusiveSystemId(u32);
 
 impl SystemRegistry {
     /// Registers a system in the [`SystemRegistry`], so it can be run later.
     ///
-    /// Repeatedly registering a system will have no effect.
+    /// Repeatedly registering the same system type will have no effect: the [`SystemId`] and
+    /// cached state (e.g. [`Local`](crate::system::Local) variables, change detection) from the
+    /// first registration are reused.
     #[inline]
-    pub fn register<M, S: IntoSystem<(), (), M> + 'static>(&mut self, system: S) -> SystemId {
+    pub fn register<I, O, M, S: IntoSystem<I, O, M> + 'static>(&mut self, system: S) -> SystemId<I, O>
+    where
+        I: 'static,
+        O: 'static,
+    {
+        let type_id = TypeId::of::<S>();
+        if let Some(&id) = self.ids_by_type.get(&type_id) {
+            return SystemId::new(id);
+        }
+
         let id = self.last_id + 1;
         self.last_id = id;
-        self.systems
-            .insert(id, (false, Box::new(IntoSystem::into_system(system))));
-        SystemId(id)
+        let boxed_system: BoxedSystem<I, O> = Box::new(IntoSystem::into_system(system));
+        let name = boxed_system.name();
+        self.systems.insert(
+            id,
+            SystemEntry {
+                initialized: false,
+                name,
+                system: Box::new(boxed_system),
+            },
+        );
+        self.ids_by_type.insert(type_id, id);
+        SystemId::new(id)
     }
 
-    /// Removes a registered system from the [`SystemRegistry`], if the [`SystemId`] is not
-    /// registered, this function does nothing.
+    /// Sets a cap on how deeply one-shot systems may recurse into one another via
+    /// [`Commands::run_system`](crate::system::Commands::run_system) (and the other
+    /// `Commands` run methods) before recursing further is refused.
+    ///
+    /// `Commands::run_system` always funnels through the infallible
+    /// [`World::run_system`]/[`World::run_exclusive_system`] entry points, so exceeding the limit
+    /// this way panics; calling one of the fallible `*_by_id` methods (e.g.
+    /// [`World::run_system_by_id`]) directly instead returns
+    /// [`SystemRegistryError::RecursionLimitExceeded`].
+    ///
+    /// Pass `None` to allow unbounded recursion, which is the default.
     #[inline]
-    pub fn remove(&mut self, id: SystemId) {
-        self.systems.remove(&id.0);
+    pub fn set_recursion_limit(&mut self, max_depth: Option<u32>) {
+        self.max_recursion_depth = max_depth;
+    }
+
+    /// Removes a registered system from the [`SystemRegistry`], returning the boxed system so it
+    /// can be reclaimed and reused (its cached state, e.g. [`Local`](crate::system::Local)
+    /// variables, is preserved). Returns `None` if the [`SystemId`] is not registered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a system is registered under `id` but its `In`/`Out` types do not match `I`/`O`.
+    /// This can happen if `id` was obtained from [`SystemRegistry::iter_ids`], which erases the
+    /// `In`/`Out` types; consistent with [`SystemRegistry::run_by_id_with_input`], this panics
+    /// rather than silently discarding the system.
+    #[inline]
+    pub fn remove<I: 'static, O: 'static>(&mut self, id: SystemId<I, O>) -> Option<BoxedSystem<I, O>> {
+        if !self.systems.get(&id.id)?.system.is::<BoxedSystem<I, O>>() {
+            panic!("Registered system was removed with the wrong `In`/`Out` types");
+        }
+
+        let entry = self.systems.remove(&id.id)?;
+        self.ids_by_type.retain(|_, registered_id| *registered_id != id.id);
+        Some(
+            *entry
+                .system
+                .downcast::<BoxedSystem<I, O>>()
+                .unwrap_or_else(|_| unreachable!("type already checked above")),
+        )
+    }
+
+    /// Returns `true` if a system is registered under the given [`SystemId`].
+    #[inline]
+    pub fn contains<I, O>(&self, id: SystemId<I, O>) -> bool {
+        self.systems.contains_key(&id.id)
+    }
+
+    /// Returns `true` if an exclusive system is registered under the given [`ExclusiveSystemId`].
+    #[inline]
+    pub fn contains_exclusive(&self, id: ExclusiveSystemId) -> bool {
+        self.exclusive_systems.contains_key(&id.0)
+    }
+
+    /// Returns the number of systems currently registered, including exclusive systems.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.systems.len() + self.exclusive_systems.len()
+    }
+
+    /// Returns `true` if no systems, including exclusive systems, are currently registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty() && self.exclusive_systems.is_empty()
+    }
+
+    /// Iterates over the [`SystemId`]s of every currently registered (non-exclusive) system.
+    ///
+    /// The returned ids are always typed as `SystemId<(), ()>`, whatever the `In`/`Out` types of
+    /// the systems they refer to, and are only intended for introspection (e.g. listing what is
+    /// registered); running or removing a system with other `In`/`Out` types through one of
+    /// these ids panics. Use
+    /// [`SystemRegistry::iter_exclusive_ids`] to iterate over exclusive systems instead.
+    pub fn iter_ids(&self) -> impl Iterator<Item = SystemId> + '_ {
+        self.systems.keys().map(|&id| SystemId::new(id))
+    }
+
+    /// Returns the name of the system registered under the given [`SystemId`], for debugging
+    /// purposes. Returns `None` if the [`SystemId`] is not registered.
+    pub fn system_name<I, O>(&self, id: SystemId<I, O>) -> Option<Cow<'static, str>> {
+        self.systems.get(&id.id).map(|entry| entry.name.clone())
+    }
+
+    /// Iterates over the [`ExclusiveSystemId`]s of every currently registered exclusive system.
+    pub fn iter_exclusive_ids(&self) -> impl Iterator<Item = ExclusiveSystemId> + '_ {
+        self.exclusive_systems.keys().map(|&id| ExclusiveSystemId(id))
+    }
+
+    /// Returns the name of the exclusive system registered under the given [`ExclusiveSystemId`],
+    /// for debugging purposes. Returns `None` if the [`ExclusiveSystemId`] is not registered.
+    pub fn exclusive_system_name(&self, id: ExclusiveSystemId) -> Option<Cow<'static, str>> {
+        self.exclusive_systems
+            .get(&id.0)
+            .map(|(_, system)| system.name())
     }
 
     /// Runs the supplied system on the [`World`] a single time.
@@ -112,10 +316,25 @@ impl SystemRegistry {
     /// System state will not be reused between runs, so [`Local`](crate::system::Local) variables are not preserved between runs.
     /// To preserve [`Local`](crate::system::Local) variables between runs, it's possible to register and run the system by id manually.
     pub fn run<M, S: IntoSystem<(), (), M> + 'static>(&mut self, world: &mut World, system: S) {
-        let mut boxed_system: BoxedSystem = Box::new(IntoSystem::into_system(system));
+        self.run_with_input(world, system, ());
+    }
+
+    /// Runs the supplied system on the [`World`] a single time, passing in the given `input`
+    /// and returning the system's output.
+    ///
+    /// This behaves like [`SystemRegistry::run`], except it supports systems that take an
+    /// [`In`](crate::system::In) and/or return a value.
+    pub fn run_with_input<I, O, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        world: &mut World,
+        system: S,
+        input: I,
+    ) -> O {
+        let mut boxed_system: BoxedSystem<I, O> = Box::new(IntoSystem::into_system(system));
         boxed_system.initialize(world);
-        boxed_system.run((), world);
+        let output = boxed_system.run(input, world);
         boxed_system.apply_deferred(world);
+        output
     }
 
     /// Run the system by its [`SystemId`]
@@ -127,17 +346,127 @@ impl SystemRegistry {
         world: &mut World,
         id: SystemId,
     ) -> Result<(), SystemRegistryError> {
-        match self.systems.get_mut(&id.0) {
+        self.run_by_id_with_input(world, id, ())
+    }
+
+    /// Run the system by its [`SystemId`], passing in the given `input` and returning the
+    /// system's output.
+    ///
+    /// This behaves like [`SystemRegistry::run_by_id`], except it supports systems that take an
+    /// [`In`](crate::system::In) and/or return a value.
+    pub fn run_by_id_with_input<I: 'static, O: 'static>(
+        &mut self,
+        world: &mut World,
+        id: SystemId<I, O>,
+        input: I,
+    ) -> Result<O, SystemRegistryError> {
+        match self.systems.get_mut(&id.id) {
+            Some(entry) => {
+                let system = entry
+                    .system
+                    .downcast_mut::<BoxedSystem<I, O>>()
+                    .expect("Registered system was run with the wrong `In`/`Out` types");
+                if !entry.initialized {
+                    system.initialize(world);
+                    entry.initialized = true;
+                }
+                let output = system.run(input, world);
+                system.apply_deferred(world);
+                Ok(output)
+            }
+            None => Err(SystemRegistryError::SystemIdNotRegistered(id.id)),
+        }
+    }
+
+    /// Registers an exclusive (`&mut World`) system in the [`SystemRegistry`], so it can be run
+    /// later.
+    ///
+    /// Repeatedly registering the same system type will have no effect, just like
+    /// [`SystemRegistry::register`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system` is not an exclusive (`&mut World`) system. Registering an ordinary
+    /// system here instead of through [`SystemRegistry::register`] would silently discard any
+    /// commands it queues, since [`SystemRegistry::run_exclusive_by_id`] never applies deferred
+    /// commands.
+    #[inline]
+    pub fn register_exclusive<M, S: IntoSystem<(), (), M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> ExclusiveSystemId {
+        let type_id = TypeId::of::<S>();
+        if let Some(&id) = self.exclusive_ids_by_type.get(&type_id) {
+            return ExclusiveSystemId(id);
+        }
+
+        let boxed_system: BoxedSystem = Box::new(IntoSystem::into_system(system));
+        assert!(
+            boxed_system.is_exclusive(),
+            "register_exclusive only accepts exclusive (&mut World) systems; register this \
+             system with `SystemRegistry::register` instead, or its commands will never be applied"
+        );
+
+        let id = self.last_id + 1;
+        self.last_id = id;
+        self.exclusive_systems.insert(id, (false, boxed_system));
+        self.exclusive_ids_by_type.insert(type_id, id);
+        ExclusiveSystemId(id)
+    }
+
+    /// Removes a registered exclusive system from the [`SystemRegistry`], if the
+    /// [`ExclusiveSystemId`] is not registered, this function does nothing.
+    #[inline]
+    pub fn remove_exclusive(&mut self, id: ExclusiveSystemId) {
+        self.exclusive_systems.remove(&id.0);
+        self.exclusive_ids_by_type
+            .retain(|_, registered_id| *registered_id != id.0);
+    }
+
+    /// Runs the supplied exclusive system on the [`World`] a single time.
+    ///
+    /// Unlike [`SystemRegistry::run`], the system is run directly against the `World`, with no
+    /// deferred-command apply step afterwards: an exclusive system's edits to the `World` are
+    /// already visible as soon as it returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system` is not an exclusive (`&mut World`) system, since its commands would
+    /// otherwise be silently discarded (see [`SystemRegistry::register_exclusive`]).
+    pub fn run_exclusive<M, S: IntoSystem<(), (), M> + 'static>(
+        &mut self,
+        world: &mut World,
+        system: S,
+    ) {
+        let mut boxed_system: BoxedSystem = Box::new(IntoSystem::into_system(system));
+        assert!(
+            boxed_system.is_exclusive(),
+            "run_exclusive only accepts exclusive (&mut World) systems; run this system with \
+             `SystemRegistry::run` instead, or its commands will never be applied"
+        );
+        boxed_system.initialize(world);
+        boxed_system.run((), world);
+    }
+
+    /// Run the exclusive system by its [`ExclusiveSystemId`].
+    ///
+    /// Exclusive systems must be registered before they can be run.
+    #[inline]
+    pub fn run_exclusive_by_id(
+        &mut self,
+        world: &mut World,
+        id: ExclusiveSystemId,
+    ) -> Result<(), SystemRegistryError> {
+        match self.exclusive_systems.get_mut(&id.0) {
             Some((initialized, matching_system)) => {
                 if !*initialized {
                     matching_system.initialize(world);
                     *initialized = true;
                 }
                 matching_system.run((), world);
-                matching_system.apply_deferred(world);
                 Ok(())
             }
-            None => Err(SystemRegistryError::SystemIdNotRegistered(id)),
+            None => Err(SystemRegistryError::SystemIdNotRegistered(id.0)),
         }
     }
 }
@@ -147,10 +476,14 @@ impl World {
     ///
     /// Calls [`SystemRegistry::register`].
     #[inline]
-    pub fn register_system<M, S: IntoSystem<(), (), M> + 'static>(
+    pub fn register_system<I, O, M, S: IntoSystem<I, O, M> + 'static>(
         &mut self,
         system: S,
-    ) -> SystemId {
+    ) -> SystemId<I, O>
+    where
+        I: 'static,
+        O: 'static,
+    {
         if !self.contains_resource::<SystemRegistry>() {
             panic!(
                 "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
@@ -162,18 +495,43 @@ impl World {
 
     /// Runs the supplied system on the [`World`] a single time.
     ///
-    /// Calls [`SystemRegistry::run_system`].
+    /// Calls [`SystemRegistry::run`].
     #[inline]
     pub fn run_system<M, S: IntoSystem<(), (), M> + 'static>(&mut self, system: S) {
+        self.run_system_with_input(system, ());
+    }
+
+    /// Runs the supplied system on the [`World`] a single time, passing in the given `input`
+    /// and returning the system's output.
+    ///
+    /// Calls [`SystemRegistry::run_with_input`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if running this system (directly, or transitively via `Commands::run_system`)
+    /// would nest one-shot systems deeper than the [`SystemRegistry`]'s configured recursion
+    /// limit (see [`SystemRegistry::set_recursion_limit`]).
+    #[inline]
+    pub fn run_system_with_input<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        system: S,
+        input: I,
+    ) -> O {
         if !self.contains_resource::<SystemRegistry>() {
             panic!(
                 "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
             );
         }
 
-        self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.run(world, system);
+        let output = self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+            registry.run_with_input(world, system, input)
         });
+
+        self.drain_system_run_queue().expect(
+            "Recursion limit exceeded while running one-shot systems queued via `Commands`",
+        );
+
+        output
     }
 
     /// Run the systems with the provided [`SystemId`].
@@ -181,15 +539,180 @@ impl World {
     /// Calls [`SystemRegistry::run_by_id`].
     #[inline]
     pub fn run_system_by_id(&mut self, id: SystemId) -> Result<(), SystemRegistryError> {
+        self.run_system_by_id_with_input(id, ())
+    }
+
+    /// Run the system with the provided [`SystemId`], passing in the given `input` and
+    /// returning the system's output.
+    ///
+    /// Calls [`SystemRegistry::run_by_id_with_input`].
+    #[inline]
+    pub fn run_system_by_id_with_input<I: 'static, O: 'static>(
+        &mut self,
+        id: SystemId<I, O>,
+        input: I,
+    ) -> Result<O, SystemRegistryError> {
+        if !self.contains_resource::<SystemRegistry>() {
+            panic!(
+                "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
+            );
+        }
+
+        let output = self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+            registry.run_by_id_with_input(world, id, input)
+        })?;
+
+        self.drain_system_run_queue()?;
+
+        Ok(output)
+    }
+
+    /// Registers an exclusive (`&mut World`) system in the [`SystemRegistry`].
+    ///
+    /// Calls [`SystemRegistry::register_exclusive`].
+    #[inline]
+    pub fn register_exclusive_system<M, S: IntoSystem<(), (), M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> ExclusiveSystemId {
+        if !self.contains_resource::<SystemRegistry>() {
+            panic!(
+                "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
+            );
+        }
+
+        self.resource_mut::<SystemRegistry>()
+            .register_exclusive(system)
+    }
+
+    /// Runs the supplied exclusive system on the [`World`] a single time.
+    ///
+    /// Unlike [`World::run_system`], this does not borrow the [`SystemRegistry`] out of the
+    /// `World` while the system runs: an exclusive system receives `&mut World` directly, so the
+    /// registry must stay put for the system to be able to run further one-shot systems itself
+    /// (for example, to drive a sub-schedule) without panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system` is not exclusive (see [`SystemRegistry::run_exclusive`]), or if running
+    /// one-shot systems that it dispatches (directly, or via `Commands::run_system`) would nest
+    /// deeper than the [`SystemRegistry`]'s configured recursion limit (see
+    /// [`SystemRegistry::set_recursion_limit`]).
+    #[inline]
+    pub fn run_exclusive_system<M, S: IntoSystem<(), (), M> + 'static>(&mut self, system: S) {
         if !self.contains_resource::<SystemRegistry>() {
             panic!(
                 "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
             );
         }
 
-        self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.run_by_id(world, id)
-        })
+        let mut boxed_system: BoxedSystem = Box::new(IntoSystem::into_system(system));
+        assert!(
+            boxed_system.is_exclusive(),
+            "run_exclusive_system only accepts exclusive (&mut World) systems; run this system \
+             with `World::run_system` instead, or its commands will never be applied"
+        );
+        boxed_system.initialize(self);
+        boxed_system.run((), self);
+
+        self.drain_system_run_queue().expect(
+            "Recursion limit exceeded while running one-shot systems queued via `Commands`",
+        );
+    }
+
+    /// Run the exclusive system with the provided [`ExclusiveSystemId`].
+    ///
+    /// Exclusive systems must be registered before they can be run.
+    ///
+    /// Unlike [`World::run_system_by_id`], this does not borrow the whole [`SystemRegistry`] out
+    /// of the `World` while the system runs: only the system being run is temporarily taken out
+    /// of the registry, so the registry stays put for the system to be able to run further
+    /// one-shot systems itself (for example, to drive a sub-schedule) without panicking.
+    #[inline]
+    pub fn run_exclusive_system_by_id(
+        &mut self,
+        id: ExclusiveSystemId,
+    ) -> Result<(), SystemRegistryError> {
+        if !self.contains_resource::<SystemRegistry>() {
+            panic!(
+                "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
+            );
+        }
+
+        let Some((mut initialized, mut system)) = self
+            .resource_mut::<SystemRegistry>()
+            .exclusive_systems
+            .remove(&id.0)
+        else {
+            return Err(SystemRegistryError::SystemIdNotRegistered(id.0));
+        };
+
+        if !initialized {
+            system.initialize(self);
+            initialized = true;
+        }
+        system.run((), self);
+
+        self.resource_mut::<SystemRegistry>()
+            .exclusive_systems
+            .insert(id.0, (initialized, system));
+
+        self.drain_system_run_queue()?;
+
+        Ok(())
+    }
+
+    /// Queues `run` to be called once the [`SystemRegistry`] is no longer borrowed out of the
+    /// `World`, allowing a running one-shot system to (indirectly, via `Commands`) trigger
+    /// another one-shot system without immediately re-entering the registry. If the registry is
+    /// not borrowed out when this is called, the queue is drained right away.
+    fn defer_system_run(&mut self, run: impl FnOnce(&mut World) + Send + 'static) {
+        let mut queue = self.get_resource_or_insert_with(SystemRegistryRunQueue::default);
+        let depth = queue.current_depth + 1;
+        queue.queue.push_back((depth, Box::new(run)));
+
+        if self.contains_resource::<SystemRegistry>() {
+            self.drain_system_run_queue().expect(
+                "Recursion limit exceeded while running one-shot systems queued via `Commands`",
+            );
+        }
+    }
+
+    /// Runs every pending one-shot system that has been queued via
+    /// [`World::defer_system_run`], including any systems that are queued transitively while
+    /// draining the queue, failing fast if doing so would exceed the [`SystemRegistry`]'s
+    /// configured recursion limit.
+    ///
+    /// Each queued run carries the recursion depth of the system that queued it, rather than
+    /// inheriting whatever depth happens to be live on the Rust call stack when it is popped, so
+    /// that sibling systems dispatched one after another from the same parent are treated as
+    /// being at the same depth instead of inflating with each sibling drained.
+    fn drain_system_run_queue(&mut self) -> Result<(), SystemRegistryError> {
+        loop {
+            let (depth, run) = {
+                let Some(mut queue) = self.get_resource_mut::<SystemRegistryRunQueue>() else {
+                    return Ok(());
+                };
+                let Some(next) = queue.queue.pop_front() else {
+                    return Ok(());
+                };
+                next
+            };
+
+            let max_depth = self.resource::<SystemRegistry>().max_recursion_depth;
+            if let Some(max_depth) = max_depth {
+                if depth > max_depth {
+                    return Err(SystemRegistryError::RecursionLimitExceeded);
+                }
+            }
+
+            let previous_depth = self.resource::<SystemRegistryRunQueue>().current_depth;
+            self.resource_mut::<SystemRegistryRunQueue>().current_depth = depth;
+
+            run(self);
+
+            self.resource_mut::<SystemRegistryRunQueue>().current_depth = previous_depth;
+        }
     }
 }
 
@@ -217,7 +740,62 @@ impl<M: Send + Sync + 'static, S: IntoSystem<(), (), M> + Send + Sync + 'static>
 {
     #[inline]
     fn apply(self, world: &mut World) {
-        world.run_system(self.system);
+        // Deferred rather than run immediately, so that systems may recurse into other
+        // one-shot systems without the `SystemRegistry` resource being borrowed out already.
+        world.defer_system_run(move |world| {
+            world.run_system(self.system);
+        });
+    }
+}
+
+/// The [`Command`] type for [`SystemRegistry::run_with_input`]
+#[derive(Debug, Clone)]
+pub struct RunSystemCommandWithInput<
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    M: Send + Sync + 'static,
+    S: IntoSystem<I, O, M> + Send + Sync + 'static,
+> {
+    _phantom_marker: PhantomData<fn(M) -> O>,
+    system: S,
+    input: I,
+}
+
+impl<
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        M: Send + Sync + 'static,
+        S: IntoSystem<I, O, M> + Send + Sync + 'static,
+    > RunSystemCommandWithInput<I, O, M, S>
+{
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    #[inline]
+    #[must_use]
+    pub fn new(system: S, input: I) -> Self {
+        Self {
+            _phantom_marker: PhantomData,
+            system,
+            input,
+        }
+    }
+}
+
+impl<
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        M: Send + Sync + 'static,
+        S: IntoSystem<I, O, M> + Send + Sync + 'static,
+    > Command for RunSystemCommandWithInput<I, O, M, S>
+{
+    #[inline]
+    fn apply(self, world: &mut World) {
+        // The system's output has nowhere to go once queued as a deferred command, so it is
+        // discarded; run it directly via `World::run_system_with_input` if you need the output.
+        // Deferred rather than run immediately, so that systems may recurse into other
+        // one-shot systems without the `SystemRegistry` resource being borrowed out already.
+        world.defer_system_run(move |world| {
+            world.run_system_with_input(self.system, self.input);
+        });
     }
 }
 
@@ -237,15 +815,68 @@ impl RunSystemById {
 impl Command for RunSystemById {
     #[inline]
     fn apply(self, world: &mut World) {
-        if !world.contains_resource::<SystemRegistry>() {
-            panic!(
-                "SystemRegistry not found: Nested and recursive one-shot systems are not supported"
-            );
-        }
+        // Deferred rather than run immediately, so that systems may recurse into other
+        // one-shot systems without the `SystemRegistry` resource being borrowed out already.
+        world.defer_system_run(move |world| {
+            world
+                .run_system_by_id(self.system_id)
+                // Ideally this error should be handled more gracefully,
+                // but that's blocked on a full error handling solution for commands
+                .unwrap();
+        });
+    }
+}
+
+/// The [`Command`] type for [`SystemRegistry::run_by_id_with_input`].
+#[derive(Debug, Clone)]
+pub struct RunSystemByIdWithInput<I: Send + Sync + 'static, O: Send + Sync + 'static> {
+    system_id: SystemId<I, O>,
+    input: I,
+}
+
+impl<I: Send + Sync + 'static, O: Send + Sync + 'static> RunSystemByIdWithInput<I, O> {
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    pub fn new(system_id: SystemId<I, O>, input: I) -> Self {
+        Self { system_id, input }
+    }
+}
 
-        world.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry
-                .run_by_id(world, self.system_id)
+impl<I: Send + Sync + 'static, O: Send + Sync + 'static> Command for RunSystemByIdWithInput<I, O> {
+    #[inline]
+    fn apply(self, world: &mut World) {
+        // Deferred rather than run immediately, so that systems may recurse into other
+        // one-shot systems without the `SystemRegistry` resource being borrowed out already.
+        world.defer_system_run(move |world| {
+            world
+                .run_system_by_id_with_input(self.system_id, self.input)
+                // Ideally this error should be handled more gracefully,
+                // but that's blocked on a full error handling solution for commands
+                .unwrap();
+        });
+    }
+}
+
+/// The [`Command`] type for [`SystemRegistry::run_exclusive_by_id`].
+#[derive(Debug, Clone, Copy)]
+pub struct RunExclusiveSystemById {
+    system_id: ExclusiveSystemId,
+}
+
+impl RunExclusiveSystemById {
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    pub fn new(system_id: ExclusiveSystemId) -> Self {
+        Self { system_id }
+    }
+}
+
+impl Command for RunExclusiveSystemById {
+    #[inline]
+    fn apply(self, world: &mut World) {
+        // Deferred rather than run immediately, so that systems may recurse into other
+        // one-shot systems without the `SystemRegistry` resource being borrowed out already.
+        world.defer_system_run(move |world| {
+            world
+                .run_exclusive_system_by_id(self.system_id)
                 // Ideally this error should be handled more gracefully,
                 // but that's blocked on a full error handling solution for commands
                 .unwrap();
@@ -259,7 +890,13 @@ pub enum SystemRegistryError {
     /// A system was run by label, but no system with that label was found.
     ///
     /// Did you forget to register it?
-    SystemIdNotRegistered(SystemId),
+    SystemIdNotRegistered(u32),
+    /// Running a one-shot system would have nested deeper than the [`SystemRegistry`]'s
+    /// configured recursion limit.
+    ///
+    /// Only returned by the fallible `*_by_id` methods (e.g. [`World::run_system_by_id`]); the
+    /// infallible entry points (including recursion via `Commands::run_system`) panic instead.
+    RecursionLimitExceeded,
 }
 
 mod tests {
@@ -388,10 +1025,167 @@ mod tests {
     }
 
     #[test]
-    // This is a known limitation;
-    // if this test passes the docs must be updated
-    // to reflect the ability to chain run_system commands
+    fn register_is_idempotent() {
+        // The `Local` begins at the default value of 0
+        fn doubling(last_counter: Local<Counter>, mut counter: ResMut<Counter>) {
+            counter.0 += last_counter.0 .0;
+            last_counter.0 .0 = counter.0;
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(1));
+        let id_a = world.register_system(doubling);
+        // Registering the same system function again must reuse the cached `Local` state,
+        // rather than starting a fresh copy of it.
+        let id_b = world.register_system(doubling);
+        let _ = world.run_system_by_id(id_a);
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+        let _ = world.run_system_by_id(id_b);
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+        let _ = world.run_system_by_id(id_a);
+        assert_eq!(*world.resource::<Counter>(), Counter(4));
+    }
+
+    #[test]
+    fn system_with_input_and_output() {
+        fn add_one(In(value): In<u32>) -> u32 {
+            value + 1
+        }
+
+        let mut world = World::new();
+        let result = world.run_system_with_input(add_one, 1);
+        assert_eq!(result, 2);
+
+        let id = world.register_system(add_one);
+        let result = world.run_system_by_id_with_input(id, result).unwrap();
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn exclusive_system() {
+        fn spawn_10_entities(world: &mut World) {
+            for _ in 0..10 {
+                world.spawn_empty();
+            }
+        }
+
+        let mut world = World::new();
+        assert_eq!(world.entities.len(), 0);
+        world.run_exclusive_system(spawn_10_entities);
+        assert_eq!(world.entities.len(), 10);
+
+        let id = world.register_exclusive_system(spawn_10_entities);
+        // Registering the same exclusive system type twice returns the same id.
+        assert_eq!(id.0, world.register_exclusive_system(spawn_10_entities).0);
+        let _ = world.run_exclusive_system_by_id(id);
+        assert_eq!(world.entities.len(), 20);
+    }
+
+    #[test]
+    fn exclusive_system_can_run_a_sub_schedule() {
+        fn run_sub_schedule(world: &mut World) {
+            world.run_system(count_up);
+            world.run_system(count_up);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        // An exclusive system already holds `&mut World`, so it must be able to run further
+        // one-shot systems directly, without the `SystemRegistry` having been borrowed away for
+        // the duration of the exclusive system's body.
+        world.run_exclusive_system(run_sub_schedule);
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+
+        let id = world.register_exclusive_system(run_sub_schedule);
+        let _ = world.run_exclusive_system_by_id(id);
+        assert_eq!(*world.resource::<Counter>(), Counter(4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn register_exclusive_rejects_non_exclusive_systems() {
+        fn spawn_entity(mut commands: Commands) {
+            commands.spawn_empty();
+        }
+
+        let mut world = World::new();
+        // `spawn_entity` takes `Commands`, not `&mut World`, so its commands would never be
+        // applied if it were accepted here.
+        world.register_exclusive_system(spawn_entity);
+    }
+
+    #[test]
+    fn introspection_and_reclaiming() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        assert!(world.resource::<SystemRegistry>().is_empty());
+
+        let id = world.register_system(count_up);
+        let registry = world.resource::<SystemRegistry>();
+        assert_eq!(registry.len(), 1);
+        assert!(!registry.is_empty());
+        assert!(registry.contains(id));
+        assert_eq!(registry.iter_ids().count(), 1);
+        let name = registry.system_name(id).expect("system should be named");
+
+        let reclaimed = world
+            .resource_mut::<SystemRegistry>()
+            .remove(id)
+            .expect("system should have been registered");
+        assert_eq!(reclaimed.name(), name);
+        let registry = world.resource::<SystemRegistry>();
+        assert!(registry.is_empty());
+        assert!(!registry.contains(id));
+        assert!(registry.system_name(id).is_none());
+        assert!(world.resource_mut::<SystemRegistry>().remove(id).is_none());
+    }
+
+    #[test]
+    fn introspection_includes_exclusive_systems() {
+        fn spawn_entity(world: &mut World) {
+            world.spawn_empty();
+        }
+
+        let mut world = World::new();
+        assert!(world.resource::<SystemRegistry>().is_empty());
+
+        let id = world.register_exclusive_system(spawn_entity);
+        let registry = world.resource::<SystemRegistry>();
+        // A registry holding only exclusive systems must not report itself as empty, since that
+        // would be misleading for a registry used as a managed pool of both kinds of system.
+        assert_eq!(registry.len(), 1);
+        assert!(!registry.is_empty());
+        assert!(registry.contains_exclusive(id));
+        assert_eq!(registry.iter_exclusive_ids().count(), 1);
+        let name = registry
+            .exclusive_system_name(id)
+            .expect("exclusive system should be named");
+        assert!(name.contains("spawn_entity"));
+
+        world.resource_mut::<SystemRegistry>().remove_exclusive(id);
+        let registry = world.resource::<SystemRegistry>();
+        assert!(registry.is_empty());
+        assert!(!registry.contains_exclusive(id));
+        assert!(registry.exclusive_system_name(id).is_none());
+    }
+
+    #[test]
     #[should_panic]
+    fn remove_rejects_type_erased_id_with_mismatched_types() {
+        fn add_one(In(value): In<u32>) -> u32 {
+            value + 1
+        }
+
+        let mut world = World::new();
+        world.register_system(add_one);
+        let mut registry = world.resource_mut::<SystemRegistry>();
+        // `iter_ids` erases the `In`/`Out` types, so this id claims to be `SystemId<(), ()>` even
+        // though the registered system is actually `SystemId<u32, u32>`.
+        let erased_id = registry.iter_ids().next().expect("system should be registered");
+        registry.remove(erased_id);
+    }
+
+    #[test]
     fn system_recursion() {
         fn count_to_ten(mut counter: ResMut<Counter>, mut commands: Commands) {
             counter.0 += 1;
@@ -406,4 +1200,41 @@ mod tests {
         world.run_system(count_to_ten);
         assert_eq!(*world.resource::<Counter>(), Counter(10));
     }
+
+    #[test]
+    #[should_panic]
+    fn system_recursion_respects_configured_limit() {
+        fn count_forever(mut counter: ResMut<Counter>, mut commands: Commands) {
+            counter.0 = counter.0.wrapping_add(1);
+            commands.run_system(count_forever);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world
+            .resource_mut::<SystemRegistry>()
+            .set_recursion_limit(Some(8));
+        // `count_forever` recurses without bound, so the configured limit must kick in
+        // rather than overflowing the stack.
+        world.run_system(count_forever);
+    }
+
+    #[test]
+    fn sibling_system_dispatch_does_not_inflate_recursion_depth() {
+        fn dispatch_two_siblings(mut commands: Commands) {
+            commands.run_system(count_up);
+            commands.run_system(count_up);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world
+            .resource_mut::<SystemRegistry>()
+            .set_recursion_limit(Some(1));
+        // Two sibling one-shot systems dispatched from the same parent are both queued at the
+        // parent's depth plus one; draining one must not inflate the depth seen by the other, or
+        // this would spuriously exceed the limit of `1`.
+        world.run_system(dispatch_two_siblings);
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
 }